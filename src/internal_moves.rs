@@ -0,0 +1,2 @@
+//! Low-level move-generation passes over `Board64`, shared by the public `MoveRules` API.
+pub(crate) mod moves64;
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{BlPlacement, Board64, RotationSystem};
+use crate::moves::{Input, MovePath};
+
+/// The lateral/rotation inputs that change a falling piece's `(position, orientation)` without
+/// locking it or moving it down. Used as-is for `hard_drop_only` searches, where manually soft
+/// dropping mid-search isn't part of the modelled move set.
+const STEPS: [Input; 5] = [Input::MoveLeft, Input::MoveRight, Input::RotateCw, Input::RotateCcw, Input::Rotate180];
+
+/// `STEPS` plus the down-translate edge, used for searches where the piece can be tucked after
+/// touching down (`Drop::Softdrop` and `Drop::Gravity`) — without it, a piece spawned in open air
+/// can never descend past its spawn row, since lateral moves don't change row and rotating in open
+/// air never needs a kick that does either.
+const STEPS_WITH_SOFT_DROP: [Input; 6] =
+    [Input::MoveLeft, Input::MoveRight, Input::RotateCw, Input::RotateCcw, Input::Rotate180, Input::SoftDrop];
+
+fn apply<T: RotationSystem>(rotation_system: &T, board: &Board64, placement: BlPlacement, input: Input) -> Option<BlPlacement> {
+    let moved = match input {
+        Input::MoveLeft => placement.shift(-1, 0),
+        Input::MoveRight => placement.shift(1, 0),
+        Input::RotateCw => placement.rotate_cw(rotation_system),
+        Input::RotateCcw => placement.rotate_ccw(rotation_system),
+        Input::Rotate180 => placement.rotate_180(rotation_system),
+        Input::SoftDrop => placement.shift(0, -1),
+        Input::HardDrop => unreachable!("hard drop is resolved directly by `hard_drop`, not through `apply`"),
+    };
+    moved.filter(|it| board.is_free(it))
+}
+
+fn hard_drop(board: &Board64, placement: BlPlacement) -> BlPlacement {
+    let mut resting = placement;
+    while let Some(lower) = resting.shift(0, -1).filter(|it| board.is_free(it)) {
+        resting = lower;
+    }
+    resting
+}
+
+fn is_resting(board: &Board64, placement: &BlPlacement) -> bool {
+    placement.shift(0, -1).map_or(true, |it| !board.is_free(&it))
+}
+
+/// Drops `placement` by up to `rows_per_step` rows, stopping early if it touches down first. This
+/// is the forced descent `Drop::Gravity` applies automatically after every lateral/rotation input,
+/// so it never shows up as its own entry in a recorded `Input` path.
+fn forced_descend(board: &Board64, placement: BlPlacement, rows_per_step: u8) -> BlPlacement {
+    let mut resting = placement;
+    for _ in 0..rows_per_step {
+        match resting.shift(0, -1).filter(|it| board.is_free(it)) {
+            Some(lower) => resting = lower,
+            None => break,
+        }
+    }
+    resting
+}
+
+/// Breadth-first search over `(position, orientation)` starting at `spawn`.
+///
+/// `gravity` forces an automatic descent of up to that many rows after every lateral/rotation
+/// input, modelling `Drop::Gravity`. `hard_drop_only` restricts the recorded placements to those
+/// produced by a single terminal `HardDrop`, matching `Drop::Harddrop`'s "no tucking after
+/// touchdown" semantics, and expands only lateral/rotation edges, since a manual soft drop isn't
+/// part of that move set; otherwise the search also expands a down-translate edge and records
+/// every resting state it settles on, matching `Drop::Softdrop`. A `visited` map keyed on
+/// `(position, orientation)` also holds the full input sequence that first reached each state,
+/// which is the shortest one, since the search proceeds level by level. A
+/// second `dropped` set dedups `hard_drop_only` results on the post-drop placement, since distinct
+/// pre-drop states can land on the same resting spot.
+fn search<T: RotationSystem>(
+    rotation_system: &T,
+    board: &Board64,
+    spawn: BlPlacement,
+    gravity: Option<u8>,
+    hard_drop_only: bool,
+) -> Vec<MovePath> {
+    let steps: &[Input] = if hard_drop_only { &STEPS } else { &STEPS_WITH_SOFT_DROP };
+
+    let mut visited = HashMap::new();
+    visited.insert(spawn, Vec::new());
+
+    let mut queue = VecDeque::new();
+    queue.push_back(spawn);
+
+    let mut results = Vec::new();
+    let mut dropped = HashSet::new();
+    let mut record_resting = |placement: BlPlacement, inputs: Vec<Input>, results: &mut Vec<MovePath>| {
+        if hard_drop_only {
+            let landing = hard_drop(board, placement);
+            if !dropped.insert(landing) {
+                return;
+            }
+            let mut inputs = inputs;
+            inputs.push(Input::HardDrop);
+            results.push(MovePath { placement: landing, inputs });
+        } else if is_resting(board, &placement) {
+            results.push(MovePath { placement, inputs });
+        }
+    };
+
+    record_resting(spawn, Vec::new(), &mut results);
+
+    while let Some(current) = queue.pop_front() {
+        let inputs_to_current = visited[&current].clone();
+
+        for &input in steps {
+            let Some(mut next) = apply(rotation_system, board, current, input) else { continue };
+            if let Some(rows_per_step) = gravity {
+                next = forced_descend(board, next, rows_per_step);
+            }
+            if visited.contains_key(&next) {
+                continue;
+            }
+
+            let mut inputs = inputs_to_current.clone();
+            inputs.push(input);
+            visited.insert(next, inputs.clone());
+            queue.push_back(next);
+
+            record_resting(next, inputs, &mut results);
+        }
+    }
+
+    results
+}
+
+/// Keeps only the first (shortest) path discovered for each distinct block position, mirroring the
+/// deduplication `generate_minimized_moves` performs over plain placements.
+fn minimize(results: Vec<MovePath>) -> Vec<MovePath> {
+    let mut seen = HashSet::new();
+    results.into_iter().filter(|it| seen.insert(it.placement.position)).collect()
+}
+
+pub(crate) fn all_moves_softdrop_with_paths<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement) -> Vec<MovePath> {
+    search(rotation_system, board, spawn, None, false)
+}
+
+pub(crate) fn all_moves_harddrop_with_paths<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement) -> Vec<MovePath> {
+    search(rotation_system, board, spawn, None, true)
+}
+
+pub(crate) fn minimized_moves_softdrop_with_paths<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement) -> Vec<MovePath> {
+    minimize(all_moves_softdrop_with_paths(rotation_system, board, spawn))
+}
+
+pub(crate) fn minimized_moves_harddrop_with_paths<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement) -> Vec<MovePath> {
+    minimize(all_moves_harddrop_with_paths(rotation_system, board, spawn))
+}
+
+/// `Drop::Gravity`'s move-generation pass: the same search as softdrop, except a forced descent of
+/// up to `rows_per_step` rows is applied after every lateral/rotation input, so only placements
+/// actually reachable under that gravity are recorded.
+pub(crate) fn all_moves_gravity_with_paths<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement, rows_per_step: u8) -> Vec<MovePath> {
+    search(rotation_system, board, spawn, Some(rows_per_step), false)
+}
+
+pub(crate) fn minimized_moves_gravity_with_paths<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement, rows_per_step: u8) -> Vec<MovePath> {
+    minimize(all_moves_gravity_with_paths(rotation_system, board, spawn, rows_per_step))
+}
+
+pub(crate) fn all_moves_gravity<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement, rows_per_step: u8) -> Vec<BlPlacement> {
+    all_moves_gravity_with_paths(rotation_system, board, spawn, rows_per_step).into_iter().map(|it| it.placement).collect()
+}
+
+pub(crate) fn minimized_moves_gravity<T: RotationSystem>(rotation_system: &T, board: &Board64, spawn: BlPlacement, rows_per_step: u8) -> Vec<BlPlacement> {
+    minimize(all_moves_gravity_with_paths(rotation_system, board, spawn, rows_per_step)).into_iter().map(|it| it.placement).collect()
+}
@@ -2,13 +2,37 @@ use std::rc::Rc;
 
 use crate::{BlPlacement, Board64, RotationSystem};
 use crate::internal_moves::moves64;
-use crate::srs::SrsKickTable;
+use crate::srs::{SrsKickTable, SrsKickTableWith180, SrsKickTableX};
 
 /// A collection of piece drop types.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub enum Drop {
     #[default] Softdrop,
     Harddrop,
+    /// Drops the piece by `rows_per_step` rows after every horizontal move or rotation, before the next
+    /// input is allowed, modelling gravity-limited play such as the common "20G" cap where the piece
+    /// snaps down at once and every later input starts from wherever it has landed.
+    Gravity(u8),
+}
+
+/// A single input that manipulates a falling piece.
+/// A minimal sequence of these is what a finesse/replay tool would actually send to press.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Input {
+    MoveLeft,
+    MoveRight,
+    RotateCw,
+    RotateCcw,
+    Rotate180,
+    SoftDrop,
+    HardDrop,
+}
+
+/// A reachable placement paired with the shortest sequence of `Input`s that produces it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct MovePath {
+    pub placement: BlPlacement,
+    pub inputs: Vec<Input>,
 }
 
 /// Rules to be applied during move generation.
@@ -30,6 +54,23 @@ impl MoveRules<SrsKickTable> {
     }
 }
 
+impl MoveRules<SrsKickTableWith180> {
+    /// SRS with the guideline-style 180° kick table layered on top, so a double rotation can be kicked
+    /// wherever the table supplies kicks for it, rather than only being tried in place.
+    #[inline]
+    pub fn srs_with_180(drop: Drop) -> Self {
+        Self { rotation_system: Rc::new(SrsKickTableWith180), drop }
+    }
+}
+
+impl MoveRules<SrsKickTableX> {
+    /// The symmetric "SRS-X" kick table, with the same 180° kicks as `srs_with_180`.
+    #[inline]
+    pub fn srs_x(drop: Drop) -> Self {
+        Self { rotation_system: Rc::new(SrsKickTableX), drop }
+    }
+}
+
 impl<T> MoveRules<T> where T: RotationSystem {
     #[inline]
     pub fn new(rotation_system: Rc<T>, drop: Drop) -> Self {
@@ -49,6 +90,9 @@ impl<T> MoveRules<T> where T: RotationSystem {
                 let result = moves64::all_moves_harddrop(self.rotation_system.as_ref(), &board.into(), spawn.into());
                 result.vec()
             }
+            Drop::Gravity(rows_per_step) => {
+                moves64::all_moves_gravity(self.rotation_system.as_ref(), &board.into(), spawn.into(), rows_per_step)
+            }
         }
     }
 
@@ -66,6 +110,34 @@ impl<T> MoveRules<T> where T: RotationSystem {
                 let result = moves64::minimized_moves_harddrop(self.rotation_system.as_ref(), &board.into(), spawn.into());
                 result.vec()
             }
+            Drop::Gravity(rows_per_step) => {
+                moves64::minimized_moves_gravity(self.rotation_system.as_ref(), &board.into(), spawn.into(), rows_per_step)
+            }
+        }
+    }
+
+    /// Collect all the places that can be placed, alongside the shortest sequence of inputs that reaches each one.
+    /// Paths are found with a breadth-first search over `(position, orientation)` starting at `spawn`, expanding
+    /// the horizontal/rotation moves plus a soft-drop edge, so the first time a resting state is reached is via
+    /// a minimal input sequence. Under `Drop::Harddrop` the soft-drop edge is left out and every path ends in a
+    /// single `HardDrop`; under `Drop::Gravity` the forced descent follows each input without being recorded
+    /// in the path. Rotations are resolved through the `RotationSystem`'s kick table, so the
+    /// orientation recorded in each path matches what the kick actually produced.
+    pub fn generate_all_moves_with_paths(&self, board: impl Into<Board64>, spawn: impl Into<BlPlacement>) -> Vec<MovePath> {
+        match self.drop {
+            Drop::Softdrop => moves64::all_moves_softdrop_with_paths(self.rotation_system.as_ref(), &board.into(), spawn.into()),
+            Drop::Harddrop => moves64::all_moves_harddrop_with_paths(self.rotation_system.as_ref(), &board.into(), spawn.into()),
+            Drop::Gravity(rows_per_step) => moves64::all_moves_gravity_with_paths(self.rotation_system.as_ref(), &board.into(), spawn.into(), rows_per_step),
+        }
+    }
+
+    /// Same as `generate_all_moves_with_paths`, but collects at most one path per distinct block position,
+    /// mirroring the deduplication `generate_minimized_moves` performs on plain placements.
+    pub fn generate_minimized_moves_with_paths(&self, board: impl Into<Board64>, spawn: impl Into<BlPlacement>) -> Vec<MovePath> {
+        match self.drop {
+            Drop::Softdrop => moves64::minimized_moves_softdrop_with_paths(self.rotation_system.as_ref(), &board.into(), spawn.into()),
+            Drop::Harddrop => moves64::minimized_moves_harddrop_with_paths(self.rotation_system.as_ref(), &board.into(), spawn.into()),
+            Drop::Gravity(rows_per_step) => moves64::minimized_moves_gravity_with_paths(self.rotation_system.as_ref(), &board.into(), spawn.into(), rows_per_step),
         }
     }
 }
@@ -76,7 +148,8 @@ mod tests {
     use std::str::FromStr;
 
     use crate::*;
-    use crate::moves::MoveRules;
+    use crate::moves::{Input, MoveRules};
+    use crate::srs::SrsKickTable;
 
     #[test]
     fn generate_all_moves() {
@@ -107,4 +180,124 @@ mod tests {
         assert_eq!(moves.len(), 17);
         assert_eq!(moves.iter().filter(|it| it.position.by == 0).count(), 2);
     }
+
+    #[test]
+    fn generate_all_moves_with_paths() {
+        let board = Board64::from_str(" \
+            ..XXXXXX..\
+            ..........\
+            ..........\
+            ..........\
+        ").unwrap();
+        let rules = MoveRules::srs(Drop::Harddrop);
+        let placement = piece!(SN).with(bl(4, 20));
+        let moves = rules.generate_all_moves_with_paths(board.clone(), placement);
+        assert_eq!(moves.len(), 34);
+        assert!(moves.iter().all(|it| !it.inputs.is_empty()));
+
+        // Every recorded path must actually reproduce the placement it's paired with when replayed
+        // from `spawn`, so a BFS bug that emits a non-empty but wrong sequence can't slip through.
+        for it in &moves {
+            assert_eq!(replay(&board, placement, &it.inputs), it.placement);
+        }
+    }
+
+    #[test]
+    fn generate_all_moves_with_paths_softdrop_descends() {
+        let board = Board64::from_str(" \
+            ..XXXXXX..\
+            ..........\
+            ..........\
+            ..........\
+        ").unwrap();
+        let rules = MoveRules::srs(Drop::Softdrop);
+        let placement = piece!(SN).with(bl(4, 20));
+        let moves = rules.generate_all_moves_with_paths(board.clone(), placement);
+        // Spawn is far above the stack; without a down-translate edge in the BFS the piece can
+        // never leave row 20 on an open board like this one, so a placement well below spawn
+        // proves the soft-drop edge is actually wired in.
+        assert!(moves.iter().any(|it| it.placement.position.by < 20));
+        for it in &moves {
+            assert_eq!(replay(&board, placement, &it.inputs), it.placement);
+        }
+    }
+
+    /// Replays `inputs` from `spawn` using the same primitives the BFS in `internal_moves::moves64`
+    /// is built on, so tests can check a recorded path actually reproduces its placement.
+    fn replay(board: &Board64, spawn: BlPlacement, inputs: &[Input]) -> BlPlacement {
+        let rotation_system = SrsKickTable;
+        let mut placement = spawn;
+        for &input in inputs {
+            placement = match input {
+                Input::MoveLeft => placement.shift(-1, 0).unwrap(),
+                Input::MoveRight => placement.shift(1, 0).unwrap(),
+                Input::RotateCw => placement.rotate_cw(&rotation_system).unwrap(),
+                Input::RotateCcw => placement.rotate_ccw(&rotation_system).unwrap(),
+                Input::Rotate180 => placement.rotate_180(&rotation_system).unwrap(),
+                Input::SoftDrop => placement.shift(0, -1).unwrap(),
+                Input::HardDrop => {
+                    let mut resting = placement;
+                    while let Some(lower) = resting.shift(0, -1).filter(|it| board.is_free(it)) {
+                        resting = lower;
+                    }
+                    resting
+                }
+            };
+        }
+        placement
+    }
+
+    #[test]
+    fn generate_all_moves_20g() {
+        let board = Board64::from_str(" \
+            ..XXXXXX..\
+            ..........\
+            ..........\
+            ..........\
+        ").unwrap();
+        let rules = MoveRules::srs(Drop::Gravity(20));
+        let placement = piece!(SN).with(bl(4, 20));
+        let moves = rules.generate_all_moves(board, placement);
+        // 20G forces the piece onto the row of X's (or down a side well) after its first input, but
+        // it can still slide and rotate along whatever it lands on. That reaches every one of the 34
+        // harddrop landing spots, plus tucks under the X's from the floor of the wells, so the set is
+        // strictly larger than the harddrop one; the wells are open all the way down, so some of
+        // those placements rest on row 0.
+        assert!(moves.len() > 0);
+        assert!(moves.len() > 34);
+        assert!(moves.iter().any(|it| it.position.by == 0));
+    }
+
+    #[test]
+    fn generate_all_moves_with_180_kicks() {
+        let board = Board64::from_str(" \
+            ..XXXXXX..\
+            ..........\
+            ..........\
+            ..........\
+        ").unwrap();
+        let rules = MoveRules::srs_with_180(Drop::Harddrop);
+        let placement = piece!(SN).with(bl(4, 20));
+        let moves = rules.generate_all_moves(board, placement);
+        // Under harddrop every (orientation, column) pair already lands in one place, and all 34 are
+        // reachable with single rotations in open air; a hard drop can't tuck under the X's, so the
+        // 180° kicks neither add nor lose placements here and the count matches plain SRS.
+        assert_eq!(moves.len(), 34);
+    }
+
+    #[test]
+    fn generate_all_moves_with_srs_x() {
+        let board = Board64::from_str(" \
+            ..XXXXXX..\
+            ..........\
+            ..........\
+            ..........\
+        ").unwrap();
+        let rules = MoveRules::srs_x(Drop::Harddrop);
+        let placement = piece!(SN).with(bl(4, 20));
+        let moves = rules.generate_all_moves(board, placement);
+        // `SrsKickTableX` layers the same 180° kicks as `SrsKickTableWith180` on top of the symmetric
+        // 90° table; as there, harddrop on this board reaches exactly the 34 plain-SRS placements.
+        assert_eq!(moves.len(), 34);
+    }
 }
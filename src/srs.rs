@@ -0,0 +1,162 @@
+use crate::{Orientation, Piece, RotationSystem};
+
+type Kicks = &'static [(i32, i32)];
+
+const NO_KICKS: Kicks = &[(0, 0)];
+
+mod jlstz {
+    use super::Kicks;
+
+    pub(super) const CW_0R: Kicks = &[(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)];
+    pub(super) const CCW_R0: Kicks = &[(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)];
+    pub(super) const CW_R2: Kicks = &[(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)];
+    pub(super) const CCW_2R: Kicks = &[(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)];
+    pub(super) const CW_2L: Kicks = &[(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)];
+    pub(super) const CCW_L2: Kicks = &[(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)];
+    pub(super) const CW_L0: Kicks = &[(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)];
+    pub(super) const CCW_0L: Kicks = &[(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)];
+}
+
+mod i_piece {
+    use super::Kicks;
+
+    pub(super) const CW_0R: Kicks = &[(0, 0), (-2, 0), (1, 0), (-2, -1), (1, 2)];
+    pub(super) const CCW_R0: Kicks = &[(0, 0), (2, 0), (-1, 0), (2, 1), (-1, -2)];
+    pub(super) const CW_R2: Kicks = &[(0, 0), (-1, 0), (2, 0), (-1, 2), (2, -1)];
+    pub(super) const CCW_2R: Kicks = &[(0, 0), (1, 0), (-2, 0), (1, -2), (-2, 1)];
+    pub(super) const CW_2L: Kicks = &[(0, 0), (2, 0), (-1, 0), (2, 1), (-1, -2)];
+    pub(super) const CCW_L2: Kicks = &[(0, 0), (-2, 0), (1, 0), (-2, -1), (1, 2)];
+    pub(super) const CW_L0: Kicks = &[(0, 0), (1, 0), (-2, 0), (1, -2), (-2, 1)];
+    pub(super) const CCW_0L: Kicks = &[(0, 0), (-1, 0), (2, 0), (-1, 2), (2, -1)];
+
+    // Vanilla SRS's `0<->L` and `2<->L` pairs are not exact mirrors of `0<->R` and `2<->R`: unlike
+    // JLSTZ, where the `L` pairs already equal the negated-x mirror of the `R` pairs, the I piece's
+    // kick *set* matches under negation but the *priority order* of the offsets doesn't. SRS-X
+    // requires the order to match too, so it gets its own mirrored constants instead of reusing the above.
+    pub(super) const CW_L0_X: Kicks = &[(0, 0), (-2, 0), (1, 0), (-2, 1), (1, -2)];
+    pub(super) const CCW_0L_X: Kicks = &[(0, 0), (2, 0), (-1, 0), (2, -1), (-1, 2)];
+    pub(super) const CW_2L_X: Kicks = &[(0, 0), (-1, 0), (2, 0), (-1, -2), (2, 1)];
+    pub(super) const CCW_L2_X: Kicks = &[(0, 0), (1, 0), (-2, 0), (1, 2), (-2, -1)];
+}
+
+/// The common 180° kick table layered on top of `SrsKickTable` by `SrsKickTableWith180`. The I
+/// piece gets its own table: its bounding box and pivot offsets differ from JLSTZ's, so reusing
+/// the JLSTZ data would offer kicks the I piece's actual footprint can't use and miss ones it can.
+mod kicks180 {
+    use super::Kicks;
+
+    pub(super) const JLSTZ_0_2: Kicks = &[(0, 0), (0, 1), (1, 1), (-1, 1), (1, 0), (-1, 0)];
+    pub(super) const JLSTZ_2_0: Kicks = &[(0, 0), (0, 1), (-1, 1), (1, 1), (-1, 0), (1, 0)];
+    pub(super) const JLSTZ_R_L: Kicks = &[(0, 0), (1, 0), (1, 2), (1, 1), (0, 2), (0, 1)];
+    pub(super) const JLSTZ_L_R: Kicks = &[(0, 0), (-1, 0), (-1, 2), (-1, 1), (0, 2), (0, 1)];
+
+    pub(super) const I_0_2: Kicks = &[(0, 0), (0, 1), (0, -1), (1, 0), (-1, 0)];
+    pub(super) const I_2_0: Kicks = &[(0, 0), (0, -1), (0, 1), (-1, 0), (1, 0)];
+    pub(super) const I_R_L: Kicks = &[(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)];
+    pub(super) const I_L_R: Kicks = &[(0, 0), (-1, 0), (1, 0), (0, 1), (0, -1)];
+}
+
+/// The guideline Super Rotation System. 90° rotations are resolved through the standard five-kick
+/// JLSTZ/I tables; 180° rotations have no kick data of their own, so a double rotation is only tried
+/// in place, with no offset.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SrsKickTable;
+
+impl RotationSystem for SrsKickTable {
+    fn iter_kicks(&self, piece: Piece, from: Orientation, to: Orientation) -> Kicks {
+        srs_90_kicks(piece, from, to).unwrap_or(NO_KICKS)
+    }
+}
+
+/// `SrsKickTable` with a dedicated kick table for 180° rotations, so a double rotation can be kicked
+/// instead of only being tried in place, making spins/tucks that only a kicked double rotation
+/// opens up reachable as a first-class move.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SrsKickTableWith180;
+
+impl RotationSystem for SrsKickTableWith180 {
+    fn iter_kicks(&self, piece: Piece, from: Orientation, to: Orientation) -> Kicks {
+        if let Some(kicks) = srs_90_kicks(piece, from, to) {
+            return kicks;
+        }
+        srs_180_kicks(piece, from, to).unwrap_or(NO_KICKS)
+    }
+}
+
+/// A symmetric SRS variant ("SRS-X"): CW and CCW 90° kicks are exact mirrors of each other. JLSTZ
+/// already has this in vanilla SRS; for the I piece the `0<->L` and `2<->L` pairs are replaced by the
+/// mirrors of `0<->R` and `2<->R`. 180° rotations use the same kick table as `SrsKickTableWith180`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SrsKickTableX;
+
+impl RotationSystem for SrsKickTableX {
+    fn iter_kicks(&self, piece: Piece, from: Orientation, to: Orientation) -> Kicks {
+        if let Some(kicks) = srs_x_90_kicks(piece, from, to) {
+            return kicks;
+        }
+        srs_180_kicks(piece, from, to).unwrap_or(NO_KICKS)
+    }
+}
+
+fn srs_90_kicks(piece: Piece, from: Orientation, to: Orientation) -> Option<Kicks> {
+    use Orientation::*;
+
+    if piece == Piece::O {
+        return Some(NO_KICKS);
+    }
+    let is_i = piece == Piece::I;
+
+    Some(match (from, to) {
+        (North, East) => if is_i { i_piece::CW_0R } else { jlstz::CW_0R },
+        (East, North) => if is_i { i_piece::CCW_R0 } else { jlstz::CCW_R0 },
+        (East, South) => if is_i { i_piece::CW_R2 } else { jlstz::CW_R2 },
+        (South, East) => if is_i { i_piece::CCW_2R } else { jlstz::CCW_2R },
+        (South, West) => if is_i { i_piece::CW_2L } else { jlstz::CW_2L },
+        (West, South) => if is_i { i_piece::CCW_L2 } else { jlstz::CCW_L2 },
+        (West, North) => if is_i { i_piece::CW_L0 } else { jlstz::CW_L0 },
+        (North, West) => if is_i { i_piece::CCW_0L } else { jlstz::CCW_0L },
+        _ => return None,
+    })
+}
+
+fn srs_x_90_kicks(piece: Piece, from: Orientation, to: Orientation) -> Option<Kicks> {
+    use Orientation::*;
+
+    if piece == Piece::O {
+        return Some(NO_KICKS);
+    }
+    let is_i = piece == Piece::I;
+
+    // For JLSTZ, vanilla `L` pairs are already the exact negated-x mirror of the `R` pairs, so
+    // they need no separate data. The I piece's vanilla `0<->L` and `2<->L` pairs are NOT exact
+    // mirrors (the offset priority order differs even though the set matches), so they get their
+    // own `*_X` constants; the `R` pairs are the reference and reuse the vanilla data.
+    Some(match (from, to) {
+        (North, East) => if is_i { i_piece::CW_0R } else { jlstz::CW_0R },
+        (East, North) => if is_i { i_piece::CCW_R0 } else { jlstz::CCW_R0 },
+        (East, South) => if is_i { i_piece::CW_R2 } else { jlstz::CW_R2 },
+        (South, East) => if is_i { i_piece::CCW_2R } else { jlstz::CCW_2R },
+        (South, West) => if is_i { i_piece::CW_2L_X } else { jlstz::CW_2L },
+        (West, South) => if is_i { i_piece::CCW_L2_X } else { jlstz::CCW_L2 },
+        (West, North) => if is_i { i_piece::CW_L0_X } else { jlstz::CW_L0 },
+        (North, West) => if is_i { i_piece::CCW_0L_X } else { jlstz::CCW_0L },
+        _ => return None,
+    })
+}
+
+fn srs_180_kicks(piece: Piece, from: Orientation, to: Orientation) -> Option<Kicks> {
+    use Orientation::*;
+
+    if piece == Piece::O {
+        return Some(NO_KICKS);
+    }
+    let is_i = piece == Piece::I;
+
+    Some(match (from, to) {
+        (North, South) => if is_i { kicks180::I_0_2 } else { kicks180::JLSTZ_0_2 },
+        (South, North) => if is_i { kicks180::I_2_0 } else { kicks180::JLSTZ_2_0 },
+        (East, West) => if is_i { kicks180::I_R_L } else { kicks180::JLSTZ_R_L },
+        (West, East) => if is_i { kicks180::I_L_R } else { kicks180::JLSTZ_L_R },
+        _ => return None,
+    })
+}